@@ -0,0 +1,122 @@
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+use std::mem::MaybeUninit;
+use std::os::raw::c_int;
+
+/// Create a new BigInt from an `i64`
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_bigint(env: Env, value: i64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_int64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Create a new BigInt from a `u64`
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_bigint_from_u64(env: Env, value: u64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_uint64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Mutates the `out` argument to refer to a new BigInt built from a sign and 64-bit words,
+/// least significant word first. Returns `false` if the BigInt could not be created, in
+/// which case an exception is pending (e.g., a `RangeError` if there are too many words).
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_bigint_from_words(
+    out: &mut Local,
+    env: Env,
+    sign_bit: bool,
+    words: &[u64],
+) -> bool {
+    let status = napi::create_bigint_words(
+        env,
+        sign_bit as c_int,
+        words.len(),
+        words.as_ptr(),
+        out as *mut _,
+    );
+
+    status == napi::Status::Ok
+}
+
+/// Get the value of a BigInt as an `i64`, or `None` if it cannot be represented without loss
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be an NAPI value associated with the given `Env`
+pub unsafe fn value_i64(env: Env, p: Local) -> Option<i64> {
+    let (value, lossless) = value_i64_with_lossless(env, p);
+
+    if lossless {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Get the value of a BigInt as an `i64`, truncating values that do not fit
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be an NAPI value associated with the given `Env`
+pub unsafe fn value_i64_lossy(env: Env, p: Local) -> i64 {
+    value_i64_with_lossless(env, p).0
+}
+
+/// Get the value of a BigInt as a `u64`, or `None` if it cannot be represented without loss
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be an NAPI value associated with the given `Env`
+pub unsafe fn value_u64(env: Env, p: Local) -> Option<u64> {
+    let (value, lossless) = value_u64_with_lossless(env, p);
+
+    if lossless {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Get the value of a BigInt as a `u64`, truncating values that do not fit
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be an NAPI value associated with the given `Env`
+pub unsafe fn value_u64_lossy(env: Env, p: Local) -> u64 {
+    value_u64_with_lossless(env, p).0
+}
+
+unsafe fn value_i64_with_lossless(env: Env, p: Local) -> (i64, bool) {
+    let mut value = 0;
+    let mut lossless = false;
+    let status =
+        napi::get_value_bigint_int64(env, p, &mut value as *mut _, &mut lossless as *mut _);
+    assert_eq!(status, napi::Status::Ok);
+    (value, lossless)
+}
+
+unsafe fn value_u64_with_lossless(env: Env, p: Local) -> (u64, bool) {
+    let mut value = 0;
+    let mut lossless = false;
+    let status =
+        napi::get_value_bigint_uint64(env, p, &mut value as *mut _, &mut lossless as *mut _);
+    assert_eq!(status, napi::Status::Ok);
+    (value, lossless)
+}
@@ -259,10 +259,36 @@ mod napi5 {
 #[cfg(feature = "napi-6")]
 mod napi6 {
     use super::super::types::*;
-    use std::os::raw::c_void;
+    use std::os::raw::{c_int, c_void};
 
     generate!(
         extern "C" {
+            fn create_bigint_int64(env: Env, value: i64, result: *mut Value) -> Status;
+
+            fn create_bigint_uint64(env: Env, value: u64, result: *mut Value) -> Status;
+
+            fn create_bigint_words(
+                env: Env,
+                sign_bit: c_int,
+                word_count: usize,
+                words: *const u64,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_value_bigint_int64(
+                env: Env,
+                value: Value,
+                result: *mut i64,
+                lossless: *mut bool,
+            ) -> Status;
+
+            fn get_value_bigint_uint64(
+                env: Env,
+                value: Value,
+                result: *mut u64,
+                lossless: *mut bool,
+            ) -> Status;
+
             fn get_all_property_names(
                 env: Env,
                 object: Value,
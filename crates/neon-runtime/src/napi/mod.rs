@@ -1,5 +1,7 @@
 pub mod array;
 pub mod arraybuffer;
+#[cfg(feature = "napi-6")]
+pub mod bigint;
 pub mod buffer;
 pub mod call;
 pub mod convert;
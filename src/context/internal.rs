@@ -2,7 +2,7 @@ use super::ModuleContext;
 use crate::handle::Handle;
 #[cfg(feature = "legacy-runtime")]
 use crate::object::class::ClassMap;
-use crate::result::NeonResult;
+use crate::result::{NeonResult, Throw};
 use crate::types::{JsObject, JsValue};
 use neon_runtime;
 use neon_runtime::raw;
@@ -163,6 +163,7 @@ pub trait ContextInternal<'a>: Sized {
             TryCatchControl::Returned => Ok(unsafe { ok.assume_init() }),
             TryCatchControl::Threw => {
                 let err = unsafe { err.assume_init() };
+                Throw::clear_message();
                 Err(JsValue::new_internal(err))
             }
             TryCatchControl::UnexpectedErr => {
@@ -180,6 +181,7 @@ pub trait ContextInternal<'a>: Sized {
         let mut local: MaybeUninit<raw::Local> = MaybeUninit::zeroed();
         unsafe {
             if neon_runtime::error::catch_error(self.env().to_raw(), local.as_mut_ptr()) {
+                Throw::clear_message();
                 Err(JsValue::new_internal(local.assume_init()))
             } else if let Ok(result) = result {
                 Ok(result)
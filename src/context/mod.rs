@@ -494,6 +494,7 @@ pub trait Context<'a>: ContextInternal<'a> {
         unsafe {
             neon_runtime::error::throw(self.env().to_raw(), v.to_raw());
         }
+        Throw::clear_message();
         Err(Throw)
     }
 
@@ -514,20 +515,23 @@ pub trait Context<'a>: ContextInternal<'a> {
 
     /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
     fn throw_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
+        let msg = msg.as_ref();
         let err = JsError::error(self, msg)?;
-        self.throw(err)
+        self.throw(err).map_err(|_| Throw::with_message(msg))
     }
 
     /// Throws an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
     fn throw_type_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
+        let msg = msg.as_ref();
         let err = JsError::type_error(self, msg)?;
-        self.throw(err)
+        self.throw(err).map_err(|_| Throw::with_message(msg))
     }
 
     /// Throws an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class.
     fn throw_range_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
+        let msg = msg.as_ref();
         let err = JsError::range_error(self, msg)?;
-        self.throw(err)
+        self.throw(err).map_err(|_| Throw::with_message(msg))
     }
 
     #[cfg(feature = "napi-1")]
@@ -604,7 +608,9 @@ impl<'a> ModuleContext<'a> {
                     <= std::mem::align_of::<raw::HandleScope>()
             );
         }
-        Scope::with(env, |scope| f(ModuleContext { scope, exports }))
+        let result = Scope::with(env, |scope| f(ModuleContext { scope, exports }));
+        Throw::clear_message();
+        result
     }
 
     /// Convenience method for exporting a Neon function from a module.
@@ -731,7 +737,7 @@ impl<'a, T: This> CallContext<'a, T> {
         info: &'a CallbackInfo<'a>,
         f: F,
     ) -> U {
-        Scope::with(env, |scope| {
+        let result = Scope::with(env, |scope| {
             f(CallContext {
                 scope,
                 info,
@@ -739,7 +745,9 @@ impl<'a, T: This> CallContext<'a, T> {
                 arguments: None,
                 phantom_type: PhantomData,
             })
-        })
+        });
+        Throw::clear_message();
+        result
     }
 
     /// Indicates the number of arguments that were passed to the function.
@@ -817,12 +825,16 @@ impl<'a> TaskContext<'a> {
     #[cfg(feature = "legacy-runtime")]
     pub(crate) fn with<T, F: for<'b> FnOnce(TaskContext<'b>) -> T>(f: F) -> T {
         let env = Env::current();
-        Scope::with(env, |scope| f(TaskContext { scope }))
+        let result = Scope::with(env, |scope| f(TaskContext { scope }));
+        Throw::clear_message();
+        result
     }
 
     #[cfg(all(feature = "napi-4", feature = "channel-api"))]
     pub(crate) fn with_context<T, F: for<'b> FnOnce(TaskContext<'b>) -> T>(env: Env, f: F) -> T {
-        Scope::with(env, |scope| f(TaskContext { scope }))
+        let result = Scope::with(env, |scope| f(TaskContext { scope }));
+        Throw::clear_message();
+        result
     }
 }
 
@@ -843,7 +855,9 @@ pub(crate) struct FinalizeContext<'a> {
 #[cfg(feature = "napi-1")]
 impl<'a> FinalizeContext<'a> {
     pub(crate) fn with<T, F: for<'b> FnOnce(FinalizeContext<'b>) -> T>(env: Env, f: F) -> T {
-        Scope::with(env, |scope| f(FinalizeContext { scope }))
+        let result = Scope::with(env, |scope| f(FinalizeContext { scope }));
+        Throw::clear_message();
+        result
     }
 }
 
@@ -35,8 +35,11 @@
 
 use crate::context::Context;
 use crate::handle::Handle;
-use crate::types::Value;
+use crate::object::Object;
+use crate::types::{JsError, Value};
+use std::cell::RefCell;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io;
 
 /// A [unit type][unit] indicating that the JavaScript thread is throwing an exception.
 ///
@@ -45,13 +48,50 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 /// since throwing means that the JavaScript thread is unavailable until the exception
 /// is handled.
 ///
+/// Reading the pending exception requires the JavaScript engine, so a `Throw` cannot
+/// inspect it after the fact. Instead, throwing APIs that know the error message in
+/// Rust, such as [`Context::throw_error`](crate::context::Context::throw_error), record
+/// it for the thread when the exception is thrown. It is available for logging with
+/// [`Throw::pending_message`](Throw::pending_message), and `Display` prints it. The
+/// message describes the thread's pending exception, not a particular `Throw` value.
+///
 /// [unit]: https://doc.rust-lang.org/book/ch05-01-defining-structs.html#unit-like-structs-without-any-fields
 #[derive(Debug)]
 pub struct Throw;
 
+thread_local! {
+    static MESSAGE: RefCell<Option<String>> = RefCell::default();
+}
+
+impl Throw {
+    /// Records the message of the exception that was just thrown on this thread.
+    pub(crate) fn with_message<S: Into<String>>(message: S) -> Self {
+        MESSAGE.with(|m| *m.borrow_mut() = Some(message.into()));
+        Throw
+    }
+
+    /// The message of the exception pending on the current thread, if it was thrown by
+    /// a Neon API that knows its message in Rust.
+    ///
+    /// The value belongs to the pending exception rather than to any particular `Throw`:
+    /// a JavaScript thread has at most one pending exception. It is `None` once that
+    /// exception is caught with [`try_catch`](crate::context::Context::try_catch),
+    /// replaced by [`Context::throw`](crate::context::Context::throw), or returned to
+    /// JavaScript. It is also `None` on any thread other than the one that threw.
+    pub fn pending_message() -> Option<String> {
+        MESSAGE.with(|m| m.borrow().clone())
+    }
+
+    /// Forgets the recorded message once the pending exception has been caught,
+    /// replaced, or returned to JavaScript.
+    pub(crate) fn clear_message() {
+        MESSAGE.with(|m| *m.borrow_mut() = None);
+    }
+}
+
 impl Display for Throw {
     fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
-        fmt.write_str("JavaScript Error")
+        MESSAGE.with(|m| fmt.write_str(m.borrow().as_deref().unwrap_or("JavaScript Error")))
     }
 }
 
@@ -66,3 +106,132 @@ pub type JsResult<'b, T> = NeonResult<Handle<'b, T>>;
 pub trait JsResultExt<'a, V: Value> {
     fn or_throw<'b, C: Context<'b>>(self, cx: &mut C) -> JsResult<'a, V>;
 }
+
+/// The JavaScript error constructors that an [`IntoJsError`](IntoJsError) type can select.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorConstructor {
+    /// [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    Error,
+    /// [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+    TypeError,
+    /// [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+    RangeError,
+}
+
+/// A Rust error type that knows how to present itself as a JavaScript exception.
+///
+/// The [`Display`](std::fmt::Display) output becomes the error's `message`. Node.js
+/// code commonly switches on `err.code`, so implementations may also provide a
+/// `code` string such as `"ERR_INVALID_ARG_TYPE"`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "napi-1")] {
+/// # use neon::prelude::*;
+/// use neon::result::{ErrorConstructor, IntoJsError, ResultExt};
+/// # use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct InvalidPort(u32);
+///
+/// impl fmt::Display for InvalidPort {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{} is not a valid port", self.0)
+///     }
+/// }
+///
+/// impl IntoJsError for InvalidPort {
+///     fn constructor(&self) -> ErrorConstructor {
+///         ErrorConstructor::RangeError
+///     }
+///
+///     fn code(&self) -> Option<&str> {
+///         Some("ERR_SOCKET_BAD_PORT")
+///     }
+/// }
+///
+/// fn parse_port(port: u32) -> Result<u16, InvalidPort> {
+///     if port > u16::MAX as u32 {
+///         Err(InvalidPort(port))
+///     } else {
+///         Ok(port as u16)
+///     }
+/// }
+///
+/// fn listen(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let port = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+///     let port = parse_port(port).or_throw_as(&mut cx)?;
+///     Ok(cx.number(port))
+/// }
+/// # }
+/// ```
+pub trait IntoJsError: Display {
+    /// The constructor used to create the thrown error. Defaults to `Error`.
+    fn constructor(&self) -> ErrorConstructor {
+        ErrorConstructor::Error
+    }
+
+    /// An optional value for the thrown error's `code` property.
+    fn code(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Errors are thrown as an `Error` with `code` set to the POSIX error name that
+/// Node.js uses for the same condition (e.g., `"ENOENT"`). The name is derived from
+/// the [`ErrorKind`](std::io::ErrorKind), so only the kinds listed below produce a
+/// `code`. Other errors, such as `EISDIR` or `ENOSPC`, are thrown without one.
+impl IntoJsError for io::Error {
+    fn code(&self) -> Option<&str> {
+        let code = match self.kind() {
+            io::ErrorKind::NotFound => "ENOENT",
+            io::ErrorKind::PermissionDenied => "EACCES",
+            io::ErrorKind::ConnectionRefused => "ECONNREFUSED",
+            io::ErrorKind::ConnectionReset => "ECONNRESET",
+            io::ErrorKind::ConnectionAborted => "ECONNABORTED",
+            io::ErrorKind::NotConnected => "ENOTCONN",
+            io::ErrorKind::AddrInUse => "EADDRINUSE",
+            io::ErrorKind::AddrNotAvailable => "EADDRNOTAVAIL",
+            io::ErrorKind::BrokenPipe => "EPIPE",
+            io::ErrorKind::AlreadyExists => "EEXIST",
+            io::ErrorKind::WouldBlock => "EAGAIN",
+            io::ErrorKind::InvalidInput => "EINVAL",
+            io::ErrorKind::TimedOut => "ETIMEDOUT",
+            io::ErrorKind::Interrupted => "EINTR",
+            _ => return None,
+        };
+
+        Some(code)
+    }
+}
+
+/// Extension trait for converting Rust [`Result`](std::result::Result) values
+/// into [`NeonResult`](NeonResult) values by throwing errors that implement
+/// [`IntoJsError`](IntoJsError).
+pub trait ResultExt<T> {
+    fn or_throw_as<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T>;
+}
+
+impl<T, E: IntoJsError> ResultExt<T> for Result<T, E> {
+    fn or_throw_as<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        let e = match self {
+            Ok(v) => return Ok(v),
+            Err(e) => e,
+        };
+
+        let msg = e.to_string();
+        let err = match e.constructor() {
+            ErrorConstructor::Error => JsError::error(cx, &msg)?,
+            ErrorConstructor::TypeError => JsError::type_error(cx, &msg)?,
+            ErrorConstructor::RangeError => JsError::range_error(cx, &msg)?,
+        };
+
+        if let Some(code) = e.code() {
+            let code = cx.string(code);
+            err.set(cx, "code", code)?;
+        }
+
+        cx.throw(err).map_err(|_| Throw::with_message(msg))
+    }
+}
@@ -0,0 +1,132 @@
+use super::{build, Value, ValueInternal};
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::{Handle, Managed};
+use crate::result::{ErrorConstructor, IntoJsError, JsResult};
+use neon_runtime;
+use neon_runtime::raw;
+use std::error::Error;
+use std::fmt;
+
+/// A JavaScript BigInt value.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "napi-6")] {
+/// # use neon::prelude::*;
+/// use neon::result::ResultExt;
+/// use neon::types::JsBigInt;
+///
+/// fn increment(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+///     let n = cx.argument::<JsBigInt>(0)?.value_i64(&mut cx).or_throw_as(&mut cx)?;
+///
+///     Ok(JsBigInt::new(&mut cx, n.saturating_add(1)))
+/// }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct JsBigInt(raw::Local);
+
+impl Value for JsBigInt {}
+
+impl Managed for JsBigInt {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsBigInt(h)
+    }
+}
+
+/// The error returned when a BigInt does not fit in the requested Rust integer type.
+///
+/// It is thrown as a `RangeError` by [`or_throw_as`](crate::result::ResultExt::or_throw_as).
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct BigIntOverflow(&'static str);
+
+impl fmt::Display for BigIntOverflow {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "BigInt out of range for {}", self.0)
+    }
+}
+
+impl Error for BigIntOverflow {}
+
+impl IntoJsError for BigIntOverflow {
+    fn constructor(&self) -> ErrorConstructor {
+        ErrorConstructor::RangeError
+    }
+}
+
+impl JsBigInt {
+    /// Creates a BigInt from an `i64`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: i64) -> Handle<'a, JsBigInt> {
+        let env = cx.env().to_raw();
+        let local = unsafe { neon_runtime::bigint::new_bigint(env, value) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from a `u64`.
+    pub fn from_u64<'a, C: Context<'a>>(cx: &mut C, value: u64) -> Handle<'a, JsBigInt> {
+        let env = cx.env().to_raw();
+        let local = unsafe { neon_runtime::bigint::new_bigint_from_u64(env, value) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt of arbitrary size from its sign and magnitude. The magnitude is given
+    /// as 64-bit words, least significant word first. Throws a `RangeError` if the result
+    /// would exceed the engine's maximum BigInt size.
+    pub fn from_words<'a, C: Context<'a>>(
+        cx: &mut C,
+        negative: bool,
+        words: &[u64],
+    ) -> JsResult<'a, JsBigInt> {
+        let env = cx.env();
+        build(env, |out| unsafe {
+            neon_runtime::bigint::new_bigint_from_words(out, env.to_raw(), negative, words)
+        })
+    }
+
+    /// Gets the BigInt's value as an `i64`. It errors when the value cannot be represented
+    /// without loss.
+    pub fn value_i64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<i64, BigIntOverflow> {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::bigint::value_i64(env, self.to_raw()) }.ok_or(BigIntOverflow("i64"))
+    }
+
+    /// Gets the BigInt's value as a `u64`. It errors when the value is negative or cannot be
+    /// represented without loss.
+    pub fn value_u64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<u64, BigIntOverflow> {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::bigint::value_u64(env, self.to_raw()) }.ok_or(BigIntOverflow("u64"))
+    }
+
+    /// Gets the BigInt's value as an `i64`, keeping only its lowest 64 bits when it
+    /// does not fit.
+    pub fn value_i64_lossy<'a, C: Context<'a>>(self, cx: &mut C) -> i64 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::bigint::value_i64_lossy(env, self.to_raw()) }
+    }
+
+    /// Gets the BigInt's value as a `u64`, keeping only its lowest 64 bits when it
+    /// does not fit.
+    pub fn value_u64_lossy<'a, C: Context<'a>>(self, cx: &mut C) -> u64 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::bigint::value_u64_lossy(env, self.to_raw()) }
+    }
+}
+
+impl ValueInternal for JsBigInt {
+    fn name() -> String {
+        "bigint".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_bigint(env.to_raw(), other.to_raw()) }
+    }
+}
@@ -68,12 +68,14 @@
 //!   - **Custom types:** [`JsBox`](JsBox), a special Neon type that allows the creation
 //!     of custom objects that own Rust data structures.
 //! - **Primitive types:** These are the built-in JavaScript datatypes that are not
-//!   object types: [`JsNumber`](JsNumber), [`JsBoolean`](JsBoolean),
+//!   object types: [`JsNumber`](JsNumber), [`JsBigInt`](JsBigInt), [`JsBoolean`](JsBoolean),
 //!   [`JsString`](JsString), [`JsNull`](JsNull), and [`JsUndefined`](JsUndefined).
 //!
 //! [types]: https://raw.githubusercontent.com/neon-bindings/neon/main/doc/types.jpg
 //! [unknown]: https://mariusschulz.com/blog/the-unknown-type-in-typescript#the-unknown-type
 
+#[cfg(feature = "napi-6")]
+pub(crate) mod bigint;
 pub(crate) mod binary;
 #[cfg(feature = "napi-1")]
 pub(crate) mod boxed;
@@ -101,6 +103,8 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 
+#[cfg(feature = "napi-6")]
+pub use self::bigint::{BigIntOverflow, JsBigInt};
 pub use self::binary::{BinaryData, BinaryViewType, JsArrayBuffer, JsBuffer};
 #[cfg(feature = "napi-1")]
 pub use self::boxed::{Finalize, JsBox};
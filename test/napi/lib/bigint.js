@@ -0,0 +1,67 @@
+var addon = require("..");
+var assert = require("chai").assert;
+
+describe("JsBigInt", function () {
+  it("should create a BigInt from an i64", function () {
+    const n = addon.create_bigint();
+    assert.strictEqual(typeof n, "bigint");
+    assert.strictEqual(n, -42n);
+  });
+
+  it("should create a BigInt from a u64", function () {
+    assert.strictEqual(addon.create_bigint_from_u64(), 2n ** 64n - 1n);
+  });
+
+  it("should create a BigInt from words", function () {
+    assert.strictEqual(addon.create_bigint_from_words(), -(2n ** 64n + 1n));
+  });
+
+  it("should get the value of a BigInt as an i64", function () {
+    assert.strictEqual(addon.bigint_value_i64(-42n), "-42");
+    assert.strictEqual(
+      addon.bigint_value_i64(2n ** 63n - 1n),
+      "9223372036854775807"
+    );
+    assert.strictEqual(
+      addon.bigint_value_i64(-(2n ** 63n)),
+      "-9223372036854775808"
+    );
+  });
+
+  it("should throw a RangeError when out of range for an i64", function () {
+    assert.throws(() => addon.bigint_value_i64(2n ** 63n), RangeError, /i64/);
+    assert.throws(() => addon.bigint_value_i64(-(2n ** 63n) - 1n), RangeError);
+  });
+
+  it("should get the value of a BigInt as a u64", function () {
+    assert.strictEqual(
+      addon.bigint_value_u64(2n ** 64n - 1n),
+      "18446744073709551615"
+    );
+  });
+
+  it("should throw a RangeError when out of range for a u64", function () {
+    assert.throws(() => addon.bigint_value_u64(2n ** 64n), RangeError, /u64/);
+    assert.throws(() => addon.bigint_value_u64(-1n), RangeError);
+  });
+
+  it("should truncate an out of range BigInt to an i64", function () {
+    assert.strictEqual(addon.bigint_value_i64_lossy(2n ** 64n + 5n), "5");
+    assert.strictEqual(
+      addon.bigint_value_i64_lossy(2n ** 63n),
+      "-9223372036854775808"
+    );
+  });
+
+  it("should truncate an out of range BigInt to a u64", function () {
+    assert.strictEqual(addon.bigint_value_u64_lossy(2n ** 64n + 5n), "5");
+    assert.strictEqual(
+      addon.bigint_value_u64_lossy(-1n),
+      "18446744073709551615"
+    );
+  });
+
+  it("should only downcast BigInt values", function () {
+    assert.throws(() => addon.bigint_value_i64(42), TypeError);
+  });
+});
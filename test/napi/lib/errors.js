@@ -1,3 +1,6 @@
+const os = require("os");
+const path = require("path");
+
 const addon = require("..");
 const assert = require("chai").assert;
 
@@ -34,8 +37,55 @@ describe("errors", function () {
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  it("should capture the thrown message in Throw", function () {
+    const msg = "Out of Bounds";
+
+    assert.strictEqual(addon.throw_message(msg), msg);
+  });
+
+  it("should clear the pending message once it is caught", function () {
+    assert.deepEqual(addon.pending_message("Out of Bounds"), [
+      "Out of Bounds",
+      undefined,
+    ]);
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
   });
+
+  it("should throw an error with the constructor and code of a Rust error", function () {
+    try {
+      addon.throw_into_js_error("port");
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.message, "invalid argument: port");
+      assert.strictEqual(err.code, "ERR_INVALID_ARG");
+      return;
+    }
+
+    assert.fail("expected an exception");
+  });
+
+  it("should capture the message of a Rust error in Throw", function () {
+    assert.strictEqual(
+      addon.throw_into_js_error_message("port"),
+      "invalid argument: port"
+    );
+  });
+
+  it("should throw an io::Error with a Node.js error code", function () {
+    const file = path.join(os.tmpdir(), "neon-file-that-does-not-exist");
+
+    try {
+      addon.throw_io_error(file);
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, "ENOENT");
+      return;
+    }
+
+    assert.fail("expected an exception");
+  });
 });
@@ -75,4 +75,16 @@ const assert = require("chai").assert;
     // Asynchronously GC to give the task queue a chance to execute
     setTimeout(() => global.gc(), 10);
   });
+
+  it("should finalize an external ArrayBuffer", function (cb) {
+    (function () {
+      const expected = "String to copy";
+      const buf = addon.finalize_external_array_buffer(expected, cb);
+
+      assert.strictEqual(Buffer.from(buf).toString(), expected);
+    })();
+
+    // Asynchronously GC to give the finalizer a chance to execute
+    setTimeout(() => global.gc(), 10);
+  });
 });
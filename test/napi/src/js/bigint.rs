@@ -0,0 +1,46 @@
+use neon::prelude::*;
+use neon::result::ResultExt;
+use neon::types::JsBigInt;
+
+pub fn create_bigint(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::new(&mut cx, -42))
+}
+
+pub fn create_bigint_from_u64(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::from_u64(&mut cx, u64::MAX))
+}
+
+pub fn create_bigint_from_words(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    // -(2^64 + 1)
+    JsBigInt::from_words(&mut cx, true, &[1, 1])
+}
+
+pub fn bigint_value_i64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx
+        .argument::<JsBigInt>(0)?
+        .value_i64(&mut cx)
+        .or_throw_as(&mut cx)?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_value_u64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx
+        .argument::<JsBigInt>(0)?
+        .value_u64(&mut cx)
+        .or_throw_as(&mut cx)?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_value_i64_lossy(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?.value_i64_lossy(&mut cx);
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_value_u64_lossy(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?.value_u64_lossy(&mut cx);
+
+    Ok(cx.string(n.to_string()))
+}
@@ -1,4 +1,7 @@
+use std::fmt;
+
 use neon::prelude::*;
+use neon::result::{ErrorConstructor, IntoJsError, ResultExt, Throw};
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
@@ -24,6 +27,44 @@ pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     cx.throw_error(msg)
 }
 
+pub fn throw_message(mut cx: FunctionContext) -> JsResult<JsString> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let mut message = String::new();
+
+    let _: Result<(), _> = cx.try_catch(|cx| {
+        let throw = cx.throw_range_error::<_, ()>(&msg).unwrap_err();
+        message = throw.to_string();
+        Err(throw)
+    });
+
+    Ok(cx.string(message))
+}
+
+pub fn pending_message(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let mut thrown = None;
+
+    let _: Result<(), _> = cx.try_catch(|cx| {
+        let throw = cx.throw_error::<_, ()>(&msg).unwrap_err();
+        thrown = Throw::pending_message();
+        Err(throw)
+    });
+
+    let caught = Throw::pending_message();
+    let result = cx.empty_array();
+
+    for (i, message) in vec![thrown, caught].into_iter().enumerate() {
+        let message = match message {
+            Some(message) => cx.string(message).upcast::<JsValue>(),
+            None => cx.undefined().upcast(),
+        };
+
+        result.set(&mut cx, i as u32, message)?;
+    }
+
+    Ok(result)
+}
+
 pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.string("hi");
     if let Err(e) = s.downcast::<JsNumber, _>(&mut cx) {
@@ -32,3 +73,52 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+#[derive(Debug)]
+struct InvalidArgError(String);
+
+impl fmt::Display for InvalidArgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid argument: {}", self.0)
+    }
+}
+
+impl IntoJsError for InvalidArgError {
+    fn constructor(&self) -> ErrorConstructor {
+        ErrorConstructor::TypeError
+    }
+
+    fn code(&self) -> Option<&str> {
+        Some("ERR_INVALID_ARG")
+    }
+}
+
+pub fn throw_into_js_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let arg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let result: Result<(), _> = Err(InvalidArgError(arg));
+
+    result.or_throw_as(&mut cx)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn throw_into_js_error_message(mut cx: FunctionContext) -> JsResult<JsString> {
+    let arg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let mut message = String::new();
+
+    let _: Result<(), _> = cx.try_catch(|cx| {
+        let result: Result<(), _> = Err(InvalidArgError(arg));
+        let throw = result.or_throw_as(cx).unwrap_err();
+        message = throw.to_string();
+        Err(throw)
+    });
+
+    Ok(cx.string(message))
+}
+
+pub fn throw_io_error(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    let contents = std::fs::read(path).or_throw_as(&mut cx)?;
+
+    Ok(cx.number(contents.len() as f64))
+}
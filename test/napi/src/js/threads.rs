@@ -185,3 +185,47 @@ pub fn drop_global_queue(mut cx: FunctionContext) -> JsResult<JsUndefined> {
 
     Ok(cx.undefined())
 }
+
+pub fn finalize_external_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+    struct Data {
+        bytes: Vec<u8>,
+        callback: Option<Root<JsFunction>>,
+        channel: Channel,
+    }
+
+    impl AsMut<[u8]> for Data {
+        fn as_mut(&mut self) -> &mut [u8] {
+            &mut self.bytes
+        }
+    }
+
+    // To verify that the finalizer frees the Rust allocation, the callback
+    // is called from the `Drop` impl on `Data`
+    impl Drop for Data {
+        fn drop(&mut self) {
+            if let Some(callback) = self.callback.take() {
+                self.channel.send(|mut cx| {
+                    let callback = callback.into_inner(&mut cx);
+                    let this = cx.undefined();
+                    let args = vec![cx.undefined()];
+
+                    callback.call(&mut cx, this, args)?;
+
+                    Ok(())
+                });
+            }
+        }
+    }
+
+    let bytes = cx.argument::<JsString>(0)?.value(&mut cx).into_bytes();
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    let data = Data {
+        bytes,
+        callback: Some(callback),
+        channel,
+    };
+
+    Ok(JsArrayBuffer::external(&mut cx, data))
+}
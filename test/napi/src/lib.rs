@@ -2,6 +2,7 @@ use neon::prelude::*;
 
 mod js {
     pub mod arrays;
+    pub mod bigint;
     pub mod boxed;
     pub mod coercions;
     pub mod date;
@@ -15,6 +16,7 @@ mod js {
 }
 
 use js::arrays::*;
+use js::bigint::*;
 use js::boxed::*;
 use js::coercions::*;
 use js::date::*;
@@ -209,6 +211,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("create_date_from_value", create_date_from_value)?;
     cx.export_function("create_and_get_invalid_date", create_and_get_invalid_date)?;
 
+    cx.export_function("create_bigint", create_bigint)?;
+    cx.export_function("create_bigint_from_u64", create_bigint_from_u64)?;
+    cx.export_function("create_bigint_from_words", create_bigint_from_words)?;
+    cx.export_function("bigint_value_i64", bigint_value_i64)?;
+    cx.export_function("bigint_value_u64", bigint_value_u64)?;
+    cx.export_function("bigint_value_i64_lossy", bigint_value_i64_lossy)?;
+    cx.export_function("bigint_value_u64_lossy", bigint_value_u64_lossy)?;
+
     cx.export_function("is_array", is_array)?;
     cx.export_function("is_array_buffer", is_array_buffer)?;
     cx.export_function("is_boolean", is_boolean)?;
@@ -225,7 +235,12 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("throw_message", throw_message)?;
+    cx.export_function("pending_message", pending_message)?;
     cx.export_function("downcast_error", downcast_error)?;
+    cx.export_function("throw_into_js_error", throw_into_js_error)?;
+    cx.export_function("throw_into_js_error_message", throw_into_js_error_message)?;
+    cx.export_function("throw_io_error", throw_io_error)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
@@ -257,6 +272,10 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("greeter_greet", greeter_greet)?;
     cx.export_function("leak_channel", leak_channel)?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
+    cx.export_function(
+        "finalize_external_array_buffer",
+        finalize_external_array_buffer,
+    )?;
 
     Ok(())
 }